@@ -1,8 +1,10 @@
 #![allow(clippy::needless_pass_by_value, clippy::wildcard_imports)]
 use core::str;
 use std::{
-    fs,
+    collections::HashMap,
+    env, fs,
     io::Error,
+    os::unix::process::CommandExt,
     process::{Command, Output},
 };
 
@@ -10,182 +12,152 @@ use abi_stable::std_types::{ROption, RString, RVec};
 use anyrun_plugin::*;
 use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
+use regex::{NoExpand, Regex};
 use ron::Result;
 use serde::Deserialize;
 
-#[derive(Deserialize, Default)]
-struct PowerActionConfig {
+#[derive(Deserialize, Clone, Default)]
+#[serde(default)]
+struct ActionConfig {
+    title: String,
+    description: String,
+    icon: String,
     command: String,
     confirm: bool,
+    #[serde(default = "ActionConfig::default_detach")]
+    detach: bool,
+    check_inhibitors: bool,
+    env: HashMap<String, String>,
+}
+
+impl ActionConfig {
+    fn default_detach() -> bool {
+        true
+    }
 }
 
 #[derive(Deserialize)]
 struct Config {
-    #[serde(default = "Config::default_lock_config")]
-    lock: PowerActionConfig,
-    #[serde(default = "Config::default_logout_config")]
-    logout: PowerActionConfig,
-    #[serde(default = "Config::default_poweroff_config")]
-    poweroff: PowerActionConfig,
-    #[serde(default = "Config::default_reboot_config")]
-    reboot: PowerActionConfig,
-    #[serde(default = "Config::default_suspend_config")]
-    suspend: PowerActionConfig,
-    #[serde(default = "Config::default_hibernate_config")]
-    hibernate: PowerActionConfig,
+    #[serde(default = "Config::default_actions")]
+    actions: Vec<ActionConfig>,
+    #[serde(default = "Config::default_confirm_pattern")]
+    confirm_pattern: Option<String>,
 }
 
 impl Config {
-    fn default_lock_config() -> PowerActionConfig {
-        PowerActionConfig {
-            command: String::from("loginctl lock-session"),
-            confirm: false,
-        }
-    }
-
-    fn default_logout_config() -> PowerActionConfig {
-        PowerActionConfig {
-            command: String::from("loginctl terminate-user $USER"),
-            confirm: true,
-        }
-    }
-
-    fn default_poweroff_config() -> PowerActionConfig {
-        PowerActionConfig {
-            command: String::from("systemctl -i poweroff"),
-            confirm: true,
-        }
-    }
-
-    fn default_reboot_config() -> PowerActionConfig {
-        PowerActionConfig {
-            command: String::from("systemctl -i reboot"),
-            confirm: true,
-        }
-    }
-
-    fn default_suspend_config() -> PowerActionConfig {
-        PowerActionConfig {
-            command: String::from("systemctl -i suspend"),
-            confirm: false,
-        }
+    fn default_actions() -> Vec<ActionConfig> {
+        vec![
+            ActionConfig {
+                title: String::from("Lock"),
+                description: String::from("Lock the session screen"),
+                icon: String::from("system-lock-screen"),
+                command: String::from("loginctl lock-session"),
+                confirm: false,
+                // The lock command must keep running attached to the session it locks.
+                detach: false,
+                check_inhibitors: false,
+                env: HashMap::new(),
+            },
+            ActionConfig {
+                title: String::from("Log out"),
+                description: String::from("Terminate the session"),
+                icon: String::from("system-log-out"),
+                command: String::from("loginctl terminate-user $USER"),
+                confirm: true,
+                detach: true,
+                check_inhibitors: false,
+                env: HashMap::new(),
+            },
+            ActionConfig {
+                title: String::from("Power off"),
+                description: String::from("Shut down the system"),
+                icon: String::from("system-shutdown"),
+                command: String::from("systemctl -i poweroff"),
+                confirm: true,
+                detach: true,
+                check_inhibitors: true,
+                env: HashMap::new(),
+            },
+            ActionConfig {
+                title: String::from("Reboot"),
+                description: String::from("Restart the system"),
+                icon: String::from("system-reboot"),
+                command: String::from("systemctl -i reboot"),
+                confirm: true,
+                detach: true,
+                check_inhibitors: true,
+                env: HashMap::new(),
+            },
+            ActionConfig {
+                title: String::from("Suspend"),
+                description: String::from("Suspend the system to RAM"),
+                icon: String::from("system-suspend"),
+                command: String::from("systemctl -i suspend"),
+                confirm: false,
+                detach: true,
+                check_inhibitors: true,
+                env: HashMap::new(),
+            },
+            ActionConfig {
+                title: String::from("Hibernate"),
+                description: String::from("Suspend the system to disk"),
+                icon: String::from("system-suspend-hibernate"),
+                command: String::from("systemctl -i hibernate"),
+                confirm: false,
+                detach: true,
+                check_inhibitors: true,
+                env: HashMap::new(),
+            },
+        ]
     }
 
-    fn default_hibernate_config() -> PowerActionConfig {
-        PowerActionConfig {
-            command: String::from("systemctl -i hibernate"),
-            confirm: false,
-        }
+    fn default_confirm_pattern() -> Option<String> {
+        Some(String::from(r"poweroff|reboot|shutdown|rm\s"))
     }
 
-    const fn get_action_config(&self, action: PowerAction) -> &PowerActionConfig {
-        match action {
-            PowerAction::Lock => &self.lock,
-            PowerAction::Logout => &self.logout,
-            PowerAction::Poweroff => &self.poweroff,
-            PowerAction::Reboot => &self.reboot,
-            PowerAction::Suspend => &self.suspend,
-            PowerAction::Hibernate => &self.hibernate,
-        }
+    fn get_action_config(&self, index: usize) -> Option<&ActionConfig> {
+        self.actions.get(index)
     }
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
-            lock: Self::default_lock_config(),
-            logout: Self::default_logout_config(),
-            poweroff: Self::default_poweroff_config(),
-            reboot: Self::default_reboot_config(),
-            suspend: Self::default_suspend_config(),
-            hibernate: Self::default_hibernate_config(),
+            actions: Self::default_actions(),
+            confirm_pattern: Self::default_confirm_pattern(),
         }
     }
 }
 
-#[derive(Clone, Copy, IntoPrimitive, TryFromPrimitive)]
-#[repr(u64)]
-enum PowerAction {
-    Lock,
-    Logout,
-    Poweroff,
-    Reboot,
-    Suspend,
-    Hibernate,
-}
-
-impl PowerAction {
-    const VALUES: [Self; 6] = [
-        Self::Lock,
-        Self::Logout,
-        Self::Poweroff,
-        Self::Reboot,
-        Self::Suspend,
-        Self::Hibernate,
-    ];
-
-    const fn get_title(&self) -> &str {
-        match self {
-            Self::Lock => "Lock",
-            Self::Logout => "Log out",
-            Self::Poweroff => "Power off",
-            Self::Reboot => "Reboot",
-            Self::Suspend => "Suspend",
-            Self::Hibernate => "Hibernate",
-        }
-    }
-    const fn get_description(&self) -> &str {
-        match self {
-            Self::Lock => "Lock the session screen",
-            Self::Logout => "Terminate the session",
-            Self::Poweroff => "Shut down the system",
-            Self::Reboot => "Restart the system",
-            Self::Suspend => "Suspend the system to RAM",
-            Self::Hibernate => "Suspend the system to disk",
-        }
-    }
-
-    const fn get_icon_name(&self) -> &str {
-        match self {
-            Self::Lock => "system-lock-screen",
-            Self::Logout => "system-log-out",
-            Self::Poweroff => "system-shutdown",
-            Self::Reboot => "system-reboot",
-            Self::Suspend => "system-suspend",
-            Self::Hibernate => "system-suspend-hibernate",
-        }
-    }
-
-    fn as_match(self) -> Match {
-        Match {
-            title: self.get_title().into(),
-            icon: ROption::RSome(self.get_icon_name().into()),
-            use_pango: false,
-            description: ROption::RSome(self.get_description().into()),
-            id: ROption::RSome(self.into()),
-        }
-    }
+fn get_fuzzy_matching_actions(actions: &[ActionConfig], phrase: &str) -> Vec<usize> {
+    let fuzzy_matcher = SkimMatcherV2::default().ignore_case();
+    let mut matches_with_scores = actions
+        .iter()
+        .enumerate()
+        .filter_map(|(index, action)| {
+            get_fuzzy_score(&fuzzy_matcher, action, phrase).map(|score| (index, score))
+        })
+        .collect::<Vec<_>>();
+    matches_with_scores.sort_by_key(|(_index, score)| *score);
+    matches_with_scores
+        .into_iter()
+        .map(|(index, _score)| index)
+        .collect()
+}
 
-    fn get_fuzzy_matching_values(phrase: &str) -> impl Iterator<Item = Self> {
-        let fuzzy_matcher = SkimMatcherV2::default().ignore_case();
-        let mut matches_with_scores = Self::VALUES
-            .into_iter()
-            .filter_map(|action| {
-                action
-                    .get_fuzzy_score(&fuzzy_matcher, phrase)
-                    .map(|score| (action, score))
-            })
-            .collect::<Vec<_>>();
-        matches_with_scores.sort_by_key(|(_action, score)| *score);
-        matches_with_scores
-            .into_iter()
-            .map(|(action, _score)| action)
-    }
+fn get_fuzzy_score(matcher: &impl FuzzyMatcher, action: &ActionConfig, phrase: &str) -> Option<i64> {
+    matcher
+        .fuzzy_match(&action.title, phrase)
+        .max(matcher.fuzzy_match(&action.description, phrase))
+}
 
-    fn get_fuzzy_score(self, matcher: &impl FuzzyMatcher, phrase: &str) -> Option<i64> {
-        matcher
-            .fuzzy_match(self.get_title(), phrase)
-            .max(matcher.fuzzy_match(self.get_description(), phrase))
+fn action_as_match(index: usize, action: &ActionConfig) -> Match {
+    Match {
+        title: action.title.as_str().into(),
+        icon: ROption::RSome(action.icon.as_str().into()),
+        use_pango: false,
+        description: ROption::RSome(action.description.as_str().into()),
+        id: ROption::RSome(index as u64),
     }
 }
 
@@ -204,7 +176,10 @@ impl ConfirmAction {
 
 pub struct State {
     config: Config,
-    pending_action: Option<PowerAction>,
+    confirm_pattern: Option<Regex>,
+    pending_action: Option<usize>,
+    pending_confirm_reason: Option<String>,
+    inhibitor_warning: Option<String>,
     error_message: Option<String>,
 }
 
@@ -214,10 +189,17 @@ fn init(config_dir: RString) -> State {
         |_err| Config::default(),
         |content| ron::from_str(&content).unwrap_or_default(),
     );
+    let confirm_pattern = config
+        .confirm_pattern
+        .as_deref()
+        .and_then(|pattern| Regex::new(pattern).ok());
 
     State {
         config,
+        confirm_pattern,
         pending_action: None,
+        pending_confirm_reason: None,
+        inhibitor_warning: None,
         error_message: None,
     }
 }
@@ -234,23 +216,61 @@ fn info() -> PluginInfo {
 fn get_matches(input: RString, state: &State) -> RVec<Match> {
     if let Some(ref error_message) = state.error_message {
         get_error_matches(error_message)
-    } else if let Some(pending_action) = state.pending_action {
-        get_confirm_matches(pending_action)
+    } else if let Some(ref inhibitor_warning) = state.inhibitor_warning {
+        get_inhibitor_matches(inhibitor_warning)
+    } else if let Some(pending_index) = state.pending_action {
+        let pending_action = state
+            .config
+            .get_action_config(pending_index)
+            .expect("pending action index refers to a configured action");
+        get_confirm_matches(pending_action, state.pending_confirm_reason.as_deref())
     } else {
-        PowerAction::get_fuzzy_matching_values(&input)
-            .map(PowerAction::as_match)
+        get_fuzzy_matching_actions(&state.config.actions, &input)
+            .into_iter()
+            .map(|index| {
+                action_as_match(
+                    index,
+                    state
+                        .config
+                        .get_action_config(index)
+                        .expect("index comes from the actions vector itself"),
+                )
+            })
             .collect()
     }
     .into()
 }
 
-fn get_confirm_matches(action_to_confirm: PowerAction) -> Vec<Match> {
+fn get_confirm_matches(action_to_confirm: &ActionConfig, danger_match: Option<&str>) -> Vec<Match> {
+    let confirm_description = danger_match.map_or_else(
+        || String::from("Proceed with the selected action"),
+        |matched| format!("Proceed with the selected action (matches confirmation pattern: \"{matched}\")"),
+    );
+    vec![
+        Match {
+            title: action_to_confirm.title.as_str().into(),
+            icon: ROption::RSome("go-next".into()),
+            use_pango: false,
+            description: ROption::RSome(confirm_description.into()),
+            id: ROption::RSome(ConfirmAction::Confirm.into()),
+        },
+        Match {
+            title: "Cancel".into(),
+            icon: ROption::RSome("go-previous".into()),
+            use_pango: false,
+            description: ROption::RSome("Abort the selected action".into()),
+            id: ROption::RSome(ConfirmAction::Cancel.into()),
+        },
+    ]
+}
+
+fn get_inhibitor_matches(inhibitor_warning: &str) -> Vec<Match> {
     vec![
         Match {
-            title: action_to_confirm.get_title().into(),
+            title: "Proceed anyway".into(),
             icon: ROption::RSome("go-next".into()),
             use_pango: false,
-            description: ROption::RSome("Proceed with the selected action".into()),
+            description: ROption::RSome(format!("Blocked by: {inhibitor_warning}").into()),
             id: ROption::RSome(ConfirmAction::Confirm.into()),
         },
         Match {
@@ -279,28 +299,104 @@ fn handler(selection: Match, state: &mut State) -> HandleResult {
         return HandleResult::Close;
     }
 
-    let power_action_config = if let Some(ref pending_action) = state.pending_action {
-        let confirm_action = ConfirmAction::try_from(selection.id.unwrap()).unwrap();
+    if state.inhibitor_warning.is_some() {
+        return handle_inhibitor_response(selection, state);
+    }
 
-        if !confirm_action.is_confirmed() {
-            state.pending_action = None;
-            return HandleResult::Refresh(false);
-        }
+    if let Some(pending_index) = state.pending_action {
+        return handle_confirm_response(selection, state, pending_index);
+    }
 
-        state.config.get_action_config(*pending_action)
-    } else {
-        let power_action = PowerAction::try_from(selection.id.unwrap()).unwrap();
-        let power_action_config = state.config.get_action_config(power_action);
+    let index = selection.id.unwrap() as usize;
+    handle_action_selected(state, index)
+}
 
-        if power_action_config.confirm {
-            state.pending_action = Some(power_action);
+fn handle_action_selected(state: &mut State, index: usize) -> HandleResult {
+    let action_config = state
+        .config
+        .get_action_config(index)
+        .expect("match id refers to a configured action");
+
+    if action_config.check_inhibitors {
+        let inhibitors = get_active_inhibitors();
+        if !inhibitors.is_empty() {
+            state.pending_action = Some(index);
+            state.inhibitor_warning = Some(inhibitors.join(", "));
             return HandleResult::Refresh(true);
-        };
+        }
+    }
 
-        power_action_config
-    };
+    require_confirm_or_run(state, index)
+}
+
+fn handle_inhibitor_response(selection: Match, state: &mut State) -> HandleResult {
+    let confirm_action = ConfirmAction::try_from(selection.id.unwrap()).unwrap();
+    state.inhibitor_warning = None;
+
+    if !confirm_action.is_confirmed() {
+        state.pending_action = None;
+        return HandleResult::Refresh(false);
+    }
+
+    let index = state
+        .pending_action
+        .expect("an inhibitor warning always carries a pending action");
+    require_confirm_or_run(state, index)
+}
+
+fn handle_confirm_response(
+    selection: Match,
+    state: &mut State,
+    pending_index: usize,
+) -> HandleResult {
+    let confirm_action = ConfirmAction::try_from(selection.id.unwrap()).unwrap();
+    state.pending_confirm_reason = None;
+
+    if !confirm_action.is_confirmed() {
+        state.pending_action = None;
+        return HandleResult::Refresh(false);
+    }
+
+    state.pending_action = None;
+    let action_config = state
+        .config
+        .get_action_config(pending_index)
+        .expect("pending action index refers to a configured action")
+        .clone();
+    run_action(state, &action_config)
+}
+
+// Decides whether `index` still needs the Confirm/Cancel step (explicit `confirm` flag or a
+// `confirm_pattern` match on its command) before running it.
+fn require_confirm_or_run(state: &mut State, index: usize) -> HandleResult {
+    let action_config = state
+        .config
+        .get_action_config(index)
+        .expect("match id refers to a configured action");
+    let danger_match = state
+        .confirm_pattern
+        .as_ref()
+        .and_then(|pattern| pattern.find(&action_config.command))
+        .map(|found| found.as_str().to_string());
+
+    if action_config.confirm || danger_match.is_some() {
+        state.pending_action = Some(index);
+        state.pending_confirm_reason = danger_match;
+        return HandleResult::Refresh(true);
+    }
+
+    state.pending_action = None;
+    let action_config = action_config.clone();
+    run_action(state, &action_config)
+}
 
-    let action_result = execute_power_action(power_action_config);
+fn run_action(state: &mut State, action_config: &ActionConfig) -> HandleResult {
+    if action_config.detach {
+        spawn_detached_power_action(action_config);
+        return HandleResult::Close;
+    }
+
+    let action_result = execute_power_action(action_config);
     let error_message = get_error_message(action_result);
     if error_message.is_some() {
         state.error_message = error_message;
@@ -310,12 +406,200 @@ fn handler(selection: Match, state: &mut State) -> HandleResult {
     HandleResult::Close
 }
 
-fn execute_power_action(action: &PowerActionConfig) -> Result<Output, std::io::Error> {
-    Command::new("/usr/bin/env")
-        .arg("sh")
-        .arg("-c")
-        .arg(&action.command)
+// Spawns the command detached from the plugin's session and TTY. Building the command (which
+// queries logind for the template placeholders) and spawning it happen on a worker thread,
+// so the caller never waits on either.
+fn spawn_detached_power_action(action: &ActionConfig) {
+    let action = action.clone();
+    std::thread::spawn(move || {
+        let mut command = build_shell_command(&action);
+        // SAFETY: `setsid` is async-signal-safe and the closure does nothing else, so it is
+        // safe to call between `fork` and `exec`.
+        unsafe {
+            command.pre_exec(|| {
+                if libc::setsid() == -1 {
+                    Err(Error::last_os_error())
+                } else {
+                    Ok(())
+                }
+            });
+        }
+        let _ = command.spawn();
+    });
+}
+
+fn execute_power_action(action: &ActionConfig) -> Result<Output, std::io::Error> {
+    build_shell_command(action).output()
+}
+
+fn build_shell_command(action: &ActionConfig) -> Command {
+    let context = resolve_template_context();
+    let expanded_command = expand_command_template(&action.command, &context);
+
+    let mut command = Command::new("/usr/bin/env");
+    command.arg("sh").arg("-c").arg(expanded_command);
+    command.envs(&action.env);
+    command
+}
+
+// Resolves the $USER/$SESSION_ID/$SEAT/$XDG_SESSION_TYPE placeholders, preferring logind's
+// view of the current session and falling back to the process environment.
+fn resolve_template_context() -> HashMap<&'static str, String> {
+    HashMap::from([
+        (
+            "USER",
+            get_logind_session_property(None, "Name")
+                .or_else(|| env::var("USER").ok())
+                .unwrap_or_default(),
+        ),
+        (
+            "SESSION_ID",
+            get_logind_session_property(None, "Id")
+                .or_else(|| env::var("XDG_SESSION_ID").ok())
+                .unwrap_or_default(),
+        ),
+        (
+            "SEAT",
+            get_logind_session_property(None, "Seat")
+                .or_else(|| env::var("XDG_SEAT").ok())
+                .unwrap_or_default(),
+        ),
+        (
+            "XDG_SESSION_TYPE",
+            get_logind_session_property(None, "Type")
+                .or_else(|| env::var("XDG_SESSION_TYPE").ok())
+                .unwrap_or_default(),
+        ),
+    ])
+}
+
+// Reads a single property (e.g. "Id", "Seat", "Type") of a logind session. `session` is the
+// session id to query, or `None` for the caller's own session.
+fn get_logind_session_property(session: Option<&str>, property: &str) -> Option<String> {
+    let mut command = Command::new("loginctl");
+    // Without an id `show-session` prints the manager's properties; "self" names the caller's
+    // own session.
+    command.arg("show-session").arg(session.unwrap_or("self"));
+    command.arg("--value").arg("-p").arg(property);
+
+    let output = command.output().ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!value.is_empty()).then_some(value)
+}
+
+fn expand_command_template(command: &str, context: &HashMap<&str, String>) -> String {
+    let mut expanded = command.to_string();
+    for (placeholder, value) in context {
+        // `\b` stops this from matching a longer name starting with `placeholder`, e.g.
+        // expanding `$USER` inside `$USERNAME`.
+        let pattern =
+            Regex::new(&format!(r"\${placeholder}\b")).expect("placeholder pattern is valid");
+        expanded = pattern.replace_all(&expanded, NoExpand(value)).into_owned();
+    }
+    expanded
+}
+
+// Lists reasons a power action might be unwelcome right now: active logind inhibitor locks
+// and other users' logged-in sessions. Returns an empty list if logind can't be reached or
+// its output can't be parsed, so a missing/odd logind never blocks an action outright.
+fn get_active_inhibitors() -> Vec<String> {
+    let mut warnings = get_inhibitor_locks();
+    warnings.extend(get_other_session_warnings());
+    warnings
+}
+
+// Lists active logind inhibitor locks as short "who: why" descriptions, e.g.
+// "Firefox: Downloading".
+fn get_inhibitor_locks() -> Vec<String> {
+    let Ok(output) = Command::new("systemd-inhibit").arg("--list").output() else {
+        return Vec::new();
+    };
+
+    if !output.status.success() {
+        return Vec::new();
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut lines = stdout.lines();
+    let Some(columns) = lines.next().and_then(locate_inhibitor_columns) else {
+        return Vec::new();
+    };
+
+    lines
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| parse_inhibitor_row(line, &columns))
+        .collect()
+}
+
+struct InhibitorColumns {
+    who: usize,
+    uid: usize,
+    why: usize,
+    mode: usize,
+}
+
+// `systemd-inhibit --list` is a fixed-width table whose "Who"/"Why" fields can contain
+// spaces (e.g. "GNOME Shell", "Watching a movie"), so columns have to be sliced by the byte
+// offset of each header, not guessed from whitespace splitting. Headers are matched without
+// regard to case, and "Who" ends where the "UID" column that follows it begins.
+fn locate_inhibitor_columns(header: &str) -> Option<InhibitorColumns> {
+    let header = header.to_ascii_uppercase();
+    Some(InhibitorColumns {
+        who: header.find("WHO")?,
+        uid: header.find("UID")?,
+        why: header.find("WHY")?,
+        mode: header.find("MODE")?,
+    })
+}
+
+fn parse_inhibitor_row(row: &str, columns: &InhibitorColumns) -> Option<String> {
+    let who = row.get(columns.who..columns.uid)?.trim();
+    let why = row.get(columns.why..columns.mode)?.trim();
+    if who.is_empty() {
+        return None;
+    }
+    Some(format!("{who}: {why}"))
+}
+
+// Lists other users' logged-in sessions as short "user: seat" descriptions, e.g. "bob:
+// seat0", so an action like poweroff doesn't silently drop someone else's session.
+fn get_other_session_warnings() -> Vec<String> {
+    let current_session = get_logind_session_property(None, "Id");
+
+    let Ok(output) = Command::new("loginctl")
+        .arg("list-sessions")
+        .arg("--no-legend")
         .output()
+    else {
+        return Vec::new();
+    };
+
+    if !output.status.success() {
+        return Vec::new();
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .filter(|session| Some(*session) != current_session.as_deref())
+        .filter_map(describe_session)
+        .collect()
+}
+
+fn describe_session(session: &str) -> Option<String> {
+    let user = get_logind_session_property(Some(session), "Name")?;
+    let seat = get_logind_session_property(Some(session), "Seat").unwrap_or_default();
+    Some(if seat.is_empty() {
+        user
+    } else {
+        format!("{user}: {seat}")
+    })
 }
 
 fn get_error_message(command_result: Result<Output, Error>) -> Option<String> {